@@ -10,7 +10,9 @@ const UNIT: u64 = 1;
 const TIME_MAGNITUDE_1: u64 = 1000;
 const TIME_MAGNITUDE_2: u64 = 60;
 const TIME_MAGNITUDE_3: u64 = 24;
-const MS: u64 = UNIT;
+const NS: u64 = UNIT;
+const US: u64 = NS * TIME_MAGNITUDE_1;
+const MS: u64 = US * TIME_MAGNITUDE_1;
 const SECOND: u64 = MS * TIME_MAGNITUDE_1;
 const MINUTE: u64 = SECOND * TIME_MAGNITUDE_2;
 const HOUR: u64 = MINUTE * TIME_MAGNITUDE_2;
@@ -20,7 +22,7 @@ const DAY: u64 = HOUR * TIME_MAGNITUDE_3;
 
 #[derive(Clone, PartialEq)]
 pub enum ConfigValue {
-    Duration(u64)
+    Duration(i64)
 }
 
 impl Display for ConfigValue {
@@ -48,20 +50,155 @@ impl From<ReadableDuration> for Duration {
 
 impl From<ReadableDuration> for ConfigValue {
     fn from(duration: ReadableDuration) -> ConfigValue {
-        ConfigValue::Duration(duration.0.as_millis() as u64)
+        let millis = crate::time::saturating_duration_to_ms(duration.0).min(i64::MAX as u64);
+        ConfigValue::Duration(millis as i64)
     }
 }
 
 impl Into<ReadableDuration> for ConfigValue {
     fn into(self) -> ReadableDuration {
         if let ConfigValue::Duration(d) = self {
-            ReadableDuration(Duration::from_millis(d))
+            if d < 0 {
+                panic!("expect: non-negative ConfigValue::Duration, got: {:?}", self);
+            }
+            ReadableDuration(Duration::from_millis(d as u64))
+        } else {
+            panic!("expect: ConfigValue::Duration, got: {:?}", self);
+        }
+    }
+}
+
+/// The sign of a [`ReadableSignedDuration`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+impl Sign {
+    /// Applies the sign to an unsigned magnitude, producing a signed value.
+    pub fn apply(self, magnitude: i64) -> i64 {
+        match self {
+            Sign::Positive => magnitude,
+            Sign::Negative => -magnitude,
+        }
+    }
+}
+
+/// A [`ReadableDuration`] paired with an explicit [`Sign`], so that negative
+/// durations (e.g. config deltas, clock-skew adjustments) can be represented
+/// without making the underlying `std::time::Duration` magnitude negative.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReadableSignedDuration {
+    sign: Sign,
+    duration: ReadableDuration,
+}
+
+impl ReadableSignedDuration {
+    pub fn positive(duration: ReadableDuration) -> ReadableSignedDuration {
+        ReadableSignedDuration {
+            sign: Sign::Positive,
+            duration,
+        }
+    }
+
+    pub fn negative(duration: ReadableDuration) -> ReadableSignedDuration {
+        ReadableSignedDuration {
+            sign: Sign::Negative,
+            duration,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.sign == Sign::Negative && !self.duration.is_zero()
+    }
+
+    pub fn as_millis(&self) -> i64 {
+        let millis = self.duration.as_millis().min(i64::MAX as u64) as i64;
+        self.sign.apply(millis)
+    }
+}
+
+impl From<ReadableSignedDuration> for ConfigValue {
+    fn from(duration: ReadableSignedDuration) -> ConfigValue {
+        ConfigValue::Duration(duration.as_millis())
+    }
+}
+
+impl Into<ReadableSignedDuration> for ConfigValue {
+    fn into(self) -> ReadableSignedDuration {
+        if let ConfigValue::Duration(ms) = self {
+            if ms < 0 {
+                ReadableSignedDuration::negative(ReadableDuration::millis(ms.unsigned_abs()))
+            } else {
+                ReadableSignedDuration::positive(ReadableDuration::millis(ms as u64))
+            }
         } else {
             panic!("expect: ConfigValue::Duration, got: {:?}", self);
         }
     }
 }
 
+impl FromStr for ReadableSignedDuration {
+    type Err = String;
+
+    fn from_str(dur_str: &str) -> Result<ReadableSignedDuration, String> {
+        let dur_str = dur_str.trim();
+        let (sign, rest) = match dur_str.strip_prefix('-') {
+            Some(rest) => (Sign::Negative, rest),
+            None => (Sign::Positive, dur_str.strip_prefix('+').unwrap_or(dur_str)),
+        };
+        let duration = rest.parse()?;
+        Ok(ReadableSignedDuration { sign, duration })
+    }
+}
+
+impl fmt::Display for ReadableSignedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.duration)
+    }
+}
+
+impl Serialize for ReadableSignedDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut buffer = String::new();
+        write!(buffer, "{}", self).unwrap();
+        serializer.serialize_str(&buffer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReadableSignedDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SignedDurVisitor;
+
+        impl<'de> Visitor<'de> for SignedDurVisitor {
+            type Value = ReadableSignedDuration;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("valid signed duration")
+            }
+
+            fn visit_str<E>(self, dur_str: &str) -> Result<ReadableSignedDuration, E>
+            where
+                E: de::Error,
+            {
+                dur_str.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(SignedDurVisitor)
+    }
+}
+
 impl FromStr for ReadableDuration {
     type Err = String;
 
@@ -70,15 +207,23 @@ impl FromStr for ReadableDuration {
         if !dur_str.is_ascii() {
             return Err(format!("unexpect ascii string: {}", dur_str));
         }
-        let err_msg = "valid duration, only d, h, m, s, ms are supported.".to_owned();
+        let err_msg = "valid duration, only d, h, m, s, ms, us, ns are supported.".to_owned();
         let mut left = dur_str.as_bytes();
         let mut last_unit = DAY + 1;
+        // Accumulated in nanoseconds, so that sub-millisecond units (`us`,
+        // `ns`) don't get rounded away before the final conversion.
         let mut dur = 0f64;
-        while let Some(idx) = left.iter().position(|c| b"dhms".contains(c)) {
+        while let Some(idx) = left.iter().position(|c| b"dhmsun".contains(c)) {
             let (first, second) = left.split_at(idx);
             let unit = if second.starts_with(b"ms") {
                 left = &left[idx + 2..];
                 MS
+            } else if second.starts_with(b"us") {
+                left = &left[idx + 2..];
+                US
+            } else if second.starts_with(b"ns") {
+                left = &left[idx + 2..];
+                NS
             } else {
                 let u = match second[0] {
                     b'd' => DAY,
@@ -91,7 +236,7 @@ impl FromStr for ReadableDuration {
                 u
             };
             if unit >= last_unit {
-                return Err("d, h, m, s, ms should occur in given order.".to_owned());
+                return Err("d, h, m, s, ms, us, ns should occur in given order.".to_owned());
             }
             // do we need to check 12h360m?
             let number_str = unsafe { str::from_utf8_unchecked(first) };
@@ -107,9 +252,10 @@ impl FromStr for ReadableDuration {
         if dur.is_sign_negative() {
             return Err("duration should be positive.".to_owned());
         }
-        let secs = dur as u64 / SECOND as u64;
-        let millis = (dur as u64 % SECOND as u64) as u32 * 1_000_000;
-        Ok(ReadableDuration(Duration::new(secs, millis)))
+        let nanos = dur as u64;
+        let secs = nanos / SECOND;
+        let subsec_nanos = (nanos % SECOND) as u32;
+        Ok(ReadableDuration(Duration::new(secs, subsec_nanos)))
     }
 }
 
@@ -137,23 +283,151 @@ impl ReadableDuration {
         ReadableDuration::hours(days * 24)
     }
 
+    pub fn micros(micros: u64) -> ReadableDuration {
+        ReadableDuration(Duration::new(
+            micros / 1_000_000,
+            (micros % 1_000_000) as u32 * 1_000,
+        ))
+    }
+
+    pub fn nanos(nanos: u64) -> ReadableDuration {
+        ReadableDuration(Duration::new(
+            nanos / 1_000_000_000,
+            (nanos % 1_000_000_000) as u32,
+        ))
+    }
+
     pub fn as_secs(&self) -> u64 {
         self.0.as_secs()
     }
 
     pub fn as_millis(&self) -> u64 {
-        crate::time::duration_to_ms(self.0)
+        crate::time::saturating_duration_to_ms(self.0)
+    }
+
+    pub fn as_nanos(&self) -> u64 {
+        crate::time::saturating_duration_to_nanos(self.0)
     }
 
     pub fn is_zero(&self) -> bool {
         self.0.as_nanos() == 0
     }
+
+    /// Parses an ISO 8601 / RFC 3339 duration, e.g. `PT1H30M15S` or `P2DT3H`,
+    /// as an alternative to the `1h30m15s` grammar used by `FromStr`.
+    pub fn from_iso8601(dur_str: &str) -> Result<ReadableDuration, String> {
+        let err_msg = "valid ISO 8601 duration, e.g. P2DT3H".to_owned();
+        let dur_str = dur_str.trim();
+        let bytes = dur_str.as_bytes();
+        if bytes.first() != Some(&b'P') {
+            return Err(err_msg);
+        }
+        let rest = &bytes[1..];
+        let (date_part, time_part) = match rest.iter().position(|&c| c == b'T') {
+            Some(p) => (&rest[..p], Some(&rest[p + 1..])),
+            None => (rest, None),
+        };
+
+        let mut nanos = 0f64;
+        let mut had_component = false;
+        for (num, unit) in iso8601_components(date_part)? {
+            had_component = true;
+            nanos += match unit {
+                b'W' => num * 7.0 * DAY as f64,
+                b'D' => num * DAY as f64,
+                _ => return Err(err_msg),
+            };
+        }
+        if let Some(time_part) = time_part {
+            for (num, unit) in iso8601_components(time_part)? {
+                had_component = true;
+                nanos += match unit {
+                    b'H' => num * HOUR as f64,
+                    b'M' => num * MINUTE as f64,
+                    b'S' => num * SECOND as f64,
+                    _ => return Err(err_msg),
+                };
+            }
+        }
+        if !had_component {
+            return Err("ISO 8601 duration must have at least one component".to_owned());
+        }
+
+        let nanos = nanos as u64;
+        Ok(ReadableDuration(Duration::new(
+            nanos / 1_000_000_000,
+            (nanos % 1_000_000_000) as u32,
+        )))
+    }
+
+    /// Formats this duration as an ISO 8601 / RFC 3339 duration string, e.g.
+    /// `PT1H30M15S`.
+    pub fn to_iso8601(&self) -> String {
+        let mut rem = crate::time::saturating_duration_to_nanos(self.0);
+        let days = rem / DAY;
+        rem %= DAY;
+        let hours = rem / HOUR;
+        rem %= HOUR;
+        let minutes = rem / MINUTE;
+        rem %= MINUTE;
+        let seconds = rem as f64 / SECOND as f64;
+
+        let mut s = String::from("P");
+        if days > 0 {
+            write!(s, "{}D", days).unwrap();
+        }
+        if hours > 0 || minutes > 0 || seconds > 0.0 {
+            s.push('T');
+            if hours > 0 {
+                write!(s, "{}H", hours).unwrap();
+            }
+            if minutes > 0 {
+                write!(s, "{}M", minutes).unwrap();
+            }
+            if seconds > 0.0 {
+                if seconds.fract() == 0.0 {
+                    write!(s, "{}S", seconds as u64).unwrap();
+                } else {
+                    write!(s, "{}S", seconds).unwrap();
+                }
+            }
+        }
+        if s == "P" {
+            s.push_str("T0S");
+        }
+        s
+    }
+}
+
+/// Splits an ISO 8601 date or time section (without the leading `P`/`T`)
+/// into its `(number, unit letter)` components, e.g. `2D3W` -> `[(2, 'D'),
+/// (3, 'W')]`.
+fn iso8601_components(section: &[u8]) -> Result<Vec<(f64, u8)>, String> {
+    let err_msg = "valid ISO 8601 duration, e.g. P2DT3H".to_owned();
+    let mut components = Vec::new();
+    let mut idx = 0;
+    while idx < section.len() {
+        let start = idx;
+        while idx < section.len() && (section[idx].is_ascii_digit() || section[idx] == b'.') {
+            idx += 1;
+        }
+        if idx == start || idx >= section.len() {
+            return Err(err_msg);
+        }
+        let num: f64 = unsafe { str::from_utf8_unchecked(&section[start..idx]) }
+            .parse()
+            .map_err(|_| err_msg.clone())?;
+        let unit = section[idx];
+        idx += 1;
+        components.push((num, unit));
+    }
+    Ok(components)
 }
 
 impl fmt::Display for ReadableDuration {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut dur = crate::time::duration_to_ms(self.0);
+        let mut dur = crate::time::saturating_duration_to_nanos(self.0);
         let mut written = false;
         if dur >= DAY {
             written = true;
@@ -175,9 +449,19 @@ impl fmt::Display for ReadableDuration {
             write!(f, "{}s", dur / SECOND)?;
             dur %= SECOND;
         }
+        if dur >= MS {
+            written = true;
+            write!(f, "{}ms", dur / MS)?;
+            dur %= MS;
+        }
+        if dur >= US {
+            written = true;
+            write!(f, "{}us", dur / US)?;
+            dur %= US;
+        }
         if dur > 0 {
             written = true;
-            write!(f, "{}ms", dur)?;
+            write!(f, "{}ns", dur)?;
         }
         if !written {
             write!(f, "0s")?;
@@ -197,28 +481,403 @@ impl Serialize for ReadableDuration {
     }
 }
 
+/// Controls how lenient [`ReadableDuration`] deserialization is about its
+/// input shape, mirroring the `Strict`/`Flexible` markers from serde_with's
+/// duration helpers.
+trait Strictness: Default {
+    /// Whether a bare number (no unit suffix) should be accepted.
+    const FLEXIBLE: bool;
+}
+
+/// Only the `"1h30m"`-style string form is accepted. This is the default.
+#[derive(Default)]
+struct Strict;
+
+/// A bare integer or float is also accepted, and interpreted in seconds.
+#[derive(Default)]
+struct Flexible;
+
+impl Strictness for Strict {
+    const FLEXIBLE: bool = false;
+}
+
+impl Strictness for Flexible {
+    const FLEXIBLE: bool = true;
+}
+
+struct DurVisitor<S>(std::marker::PhantomData<S>);
+
+impl<'de, S: Strictness> Visitor<'de> for DurVisitor<S> {
+    type Value = ReadableDuration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if S::FLEXIBLE {
+            formatter.write_str("valid duration, either a unit string or a bare number of seconds")
+        } else {
+            formatter.write_str("valid duration")
+        }
+    }
+
+    fn visit_str<E>(self, dur_str: &str) -> Result<ReadableDuration, E>
+    where
+        E: de::Error,
+    {
+        dur_str.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<ReadableDuration, E>
+    where
+        E: de::Error,
+    {
+        if S::FLEXIBLE {
+            Ok(ReadableDuration::secs(v))
+        } else {
+            Err(E::invalid_type(de::Unexpected::Unsigned(v), &self))
+        }
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<ReadableDuration, E>
+    where
+        E: de::Error,
+    {
+        if S::FLEXIBLE && v >= 0 {
+            Ok(ReadableDuration::secs(v as u64))
+        } else {
+            Err(E::invalid_type(de::Unexpected::Signed(v), &self))
+        }
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<ReadableDuration, E>
+    where
+        E: de::Error,
+    {
+        if S::FLEXIBLE && v >= 0.0 {
+            Ok(ReadableDuration::millis((v * 1000.0) as u64))
+        } else {
+            Err(E::invalid_type(de::Unexpected::Float(v), &self))
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for ReadableDuration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct DurVisitor;
+        deserializer.deserialize_str(DurVisitor::<Strict>(std::marker::PhantomData))
+    }
+}
+
+/// A serde adapter for `#[serde(with = "flexible_duration")]` fields that
+/// should accept either the usual `"1h30m"` string form or a bare
+/// integer/float number of seconds.
+pub mod flexible_duration {
+    use super::{DurVisitor, Flexible, ReadableDuration};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(duration: &ReadableDuration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        duration.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ReadableDuration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DurVisitor::<Flexible>(std::marker::PhantomData))
+    }
+}
+
+/// A serde adapter for `#[serde(with = "iso8601")]` fields that should use
+/// the ISO 8601 / RFC 3339 duration format (e.g. `PT1H30M15S`) instead of
+/// the usual `"1h30m15s"` grammar.
+pub mod iso8601 {
+    use super::ReadableDuration;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(duration: &ReadableDuration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&duration.to_iso8601())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ReadableDuration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ReadableDuration::from_iso8601(&s).map_err(de::Error::custom)
+    }
+}
+
+// TAI64N label bias: the TAI64 external format represents a TAI second count
+// as `2^62 + seconds`, so that all representable labels are positive and
+// sort correctly as unsigned integers.
+const TAI64_EPOCH_BIAS: i64 = 1 << 62;
+
+// TAI was defined to be exactly 10 seconds ahead of UTC at the 1972 epoch,
+// and every leap second inserted into UTC since then widens the gap by one
+// more second. IERS has not scheduled a new leap second as of this writing;
+// bump this constant if one is announced.
+const TAI_MINUS_UTC_SECS: i64 = 37;
+
+/// An absolute point in time encoded as TAI64N: a count of TAI seconds since
+/// 1970 plus a nanosecond field. Unlike a UTC timestamp, TAI64N is immune to
+/// leap-second ambiguity and always monotone-comparable, which makes it a
+/// good fit for scheduled-task and snapshot-retention config values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReadableInstant {
+    secs: i64,
+    nanos: u32,
+}
+
+impl ReadableInstant {
+    /// Builds a `ReadableInstant` from a Unix (UTC) timestamp.
+    pub fn from_unix(unix_secs: i64, nanos: u32) -> ReadableInstant {
+        ReadableInstant {
+            secs: unix_secs + TAI_MINUS_UTC_SECS,
+            nanos,
+        }
+    }
+
+    /// Converts back to a Unix (UTC) timestamp.
+    pub fn to_unix(&self) -> (i64, u32) {
+        (self.secs - TAI_MINUS_UTC_SECS, self.nanos)
+    }
+
+    fn to_tai64n_bytes(self) -> [u8; 12] {
+        let label = (TAI64_EPOCH_BIAS + self.secs) as u64;
+        let mut buf = [0u8; 12];
+        buf[..8].copy_from_slice(&label.to_be_bytes());
+        buf[8..].copy_from_slice(&self.nanos.to_be_bytes());
+        buf
+    }
+
+    fn from_tai64n_bytes(bytes: &[u8; 12]) -> ReadableInstant {
+        let mut label_bytes = [0u8; 8];
+        label_bytes.copy_from_slice(&bytes[..8]);
+        let label = u64::from_be_bytes(label_bytes);
+        let mut nanos_bytes = [0u8; 4];
+        nanos_bytes.copy_from_slice(&bytes[8..]);
+        ReadableInstant {
+            secs: label as i64 - TAI64_EPOCH_BIAS,
+            nanos: u32::from_be_bytes(nanos_bytes),
+        }
+    }
+
+    /// Encodes this instant as the 12-byte big-endian TAI64N representation,
+    /// hex-encoded.
+    pub fn to_hex(&self) -> String {
+        let mut s = String::with_capacity(24);
+        for b in &self.to_tai64n_bytes() {
+            write!(s, "{:02x}", b).unwrap();
+        }
+        s
+    }
+
+    /// Parses the 24-character hex encoding produced by [`to_hex`](Self::to_hex).
+    pub fn from_hex(s: &str) -> Result<ReadableInstant, String> {
+        let s = s.trim();
+        if s.len() != 24 {
+            return Err(format!("expect 24 hex chars for TAI64N, got: {}", s));
+        }
+        let mut bytes = [0u8; 12];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16)
+                .map_err(|e| format!("invalid TAI64N hex: {}", e))?;
+        }
+        Ok(ReadableInstant::from_tai64n_bytes(&bytes))
+    }
+}
+
+impl std::ops::Add<ReadableDuration> for ReadableInstant {
+    type Output = ReadableInstant;
+
+    fn add(self, rhs: ReadableDuration) -> ReadableInstant {
+        let total_nanos =
+            self.secs as i128 * 1_000_000_000 + self.nanos as i128 + rhs.as_nanos() as i128;
+        ReadableInstant {
+            secs: (total_nanos.div_euclid(1_000_000_000)) as i64,
+            nanos: total_nanos.rem_euclid(1_000_000_000) as u32,
+        }
+    }
+}
+
+impl std::ops::Sub<ReadableDuration> for ReadableInstant {
+    type Output = ReadableInstant;
+
+    fn sub(self, rhs: ReadableDuration) -> ReadableInstant {
+        let total_nanos =
+            self.secs as i128 * 1_000_000_000 + self.nanos as i128 - rhs.as_nanos() as i128;
+        ReadableInstant {
+            secs: (total_nanos.div_euclid(1_000_000_000)) as i64,
+            nanos: total_nanos.rem_euclid(1_000_000_000) as u32,
+        }
+    }
+}
+
+/// Converts a count of days since 1970-01-01 into a `(year, month, day)`
+/// civil date, using Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+impl Display for ReadableInstant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (unix_secs, nanos) = self.to_unix();
+        let days = unix_secs.div_euclid(86_400);
+        let secs_of_day = unix_secs.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        let hour = secs_of_day / 3600;
+        let minute = (secs_of_day % 3600) / 60;
+        let second = secs_of_day % 60;
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+            year, month, day, hour, minute, second, nanos
+        )
+    }
+}
+
+impl Serialize for ReadableInstant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+impl<'de> Deserialize<'de> for ReadableInstant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct InstantVisitor;
 
-        impl<'de> Visitor<'de> for DurVisitor {
-            type Value = ReadableDuration;
+        impl<'de> Visitor<'de> for InstantVisitor {
+            type Value = ReadableInstant;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("valid duration")
+                formatter.write_str("a 24-character hex-encoded TAI64N timestamp")
             }
 
-            fn visit_str<E>(self, dur_str: &str) -> Result<ReadableDuration, E>
+            fn visit_str<E>(self, s: &str) -> Result<ReadableInstant, E>
             where
                 E: de::Error,
             {
-                dur_str.parse().map_err(E::custom)
+                ReadableInstant::from_hex(s).map_err(E::custom)
             }
         }
 
-        deserializer.deserialize_str(DurVisitor)
+        deserializer.deserialize_str(InstantVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readable_duration_round_trip() {
+        for s in &["0s", "1d2h3m4s5ms6us7ns", "500ms", "1.5h"] {
+            let d: ReadableDuration = s.parse().unwrap();
+            let round_tripped: ReadableDuration = d.to_string().parse().unwrap();
+            assert_eq!(d, round_tripped, "round-trip failed for {}", s);
+        }
+    }
+
+    #[test]
+    fn test_readable_duration_iso8601_round_trip() {
+        for s in &["PT1H30M15S", "P2DT3H", "PT0S"] {
+            let d = ReadableDuration::from_iso8601(s).unwrap();
+            let round_tripped = ReadableDuration::from_iso8601(&d.to_iso8601()).unwrap();
+            assert_eq!(d, round_tripped, "round-trip failed for {}", s);
+        }
+    }
+
+    #[test]
+    fn test_readable_instant_round_trip() {
+        let instant = ReadableInstant::from_unix(1_700_000_000, 123_456_789);
+        let round_tripped = ReadableInstant::from_hex(&instant.to_hex()).unwrap();
+        assert_eq!(instant, round_tripped);
+        assert_eq!(instant.to_unix(), round_tripped.to_unix());
+    }
+
+    #[test]
+    fn test_config_value_duration_boundary() {
+        // A Duration large enough to saturate `saturating_duration_to_ms`
+        // must not wrap around to a negative `ConfigValue::Duration` when
+        // cast to `i64`.
+        let huge = ReadableDuration(Duration::new(u64::MAX, 0));
+        let ConfigValue::Duration(ms) = huge.into();
+        assert!(ms >= 0, "duration must not become negative: {}", ms);
+    }
+
+    #[test]
+    fn test_readable_duration_display_and_iso8601_do_not_overflow() {
+        // `Display`/`to_iso8601` must use the saturating nanosecond
+        // conversion: a duration this large overflows a non-saturating
+        // `as_secs() * 1_000_000_000` well before reaching `Duration::MAX`.
+        let huge = ReadableDuration(Duration::new(600 * 365 * 24 * 3600, 0));
+        assert!(!huge.to_string().is_empty());
+        assert!(!huge.to_iso8601().is_empty());
+    }
+
+    #[test]
+    fn test_readable_signed_duration_round_trip() {
+        for s in &["-1h30m", "1h30m", "0s"] {
+            let d: ReadableSignedDuration = s.parse().unwrap();
+            let round_tripped: ReadableSignedDuration = d.to_string().parse().unwrap();
+            assert_eq!(
+                d.as_millis(),
+                round_tripped.as_millis(),
+                "round-trip failed for {}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_config_value_signed_duration_boundary() {
+        // Converting `ConfigValue::Duration(i64::MIN)` back to a signed
+        // duration must not panic on negation overflow.
+        let signed: ReadableSignedDuration = ConfigValue::Duration(i64::MIN).into();
+        assert!(signed.is_negative());
+    }
+
+    #[test]
+    fn test_readable_signed_duration_as_millis_clamps_saturated_magnitude() {
+        // A saturated (huge) positive magnitude must not become negative
+        // once cast to `i64` and have the sign applied.
+        let huge = ReadableSignedDuration::positive(ReadableDuration(Duration::new(u64::MAX, 0)));
+        assert!(
+            huge.as_millis() >= 0,
+            "positive signed duration must not become negative: {}",
+            huge.as_millis()
+        );
+
+        let value: ConfigValue = huge.into();
+        match value {
+            ConfigValue::Duration(ms) => assert!(ms >= 0, "must not serialize as negative: {}", ms),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "non-negative")]
+    fn test_config_value_into_readable_duration_rejects_negative() {
+        let _: ReadableDuration = ConfigValue::Duration(-1).into();
     }
 }
\ No newline at end of file
@@ -25,4 +25,36 @@ pub fn duration_to_nanos(d: Duration) -> u64 {
     let nanos = u64::from(d.subsec_nanos());
     // Most of case, we can't have so large Duration, so here just panic if overflow now.
     d.as_secs() * 1_000_000_000 + nanos
+}
+
+/// Converts Duration to milliseconds, returning `None` instead of panicking
+/// on overflow.
+#[inline]
+pub fn checked_duration_to_ms(d: Duration) -> Option<u64> {
+    let nanos = u64::from(d.subsec_nanos());
+    d.as_secs()
+        .checked_mul(1_000)?
+        .checked_add(nanos / 1_000_000)
+}
+
+/// Converts Duration to milliseconds, saturating at `u64::MAX` on overflow
+/// instead of panicking.
+#[inline]
+pub fn saturating_duration_to_ms(d: Duration) -> u64 {
+    checked_duration_to_ms(d).unwrap_or(u64::MAX)
+}
+
+/// Converts Duration to nanoseconds, returning `None` instead of panicking
+/// on overflow.
+#[inline]
+pub fn checked_duration_to_nanos(d: Duration) -> Option<u64> {
+    let nanos = u64::from(d.subsec_nanos());
+    d.as_secs().checked_mul(1_000_000_000)?.checked_add(nanos)
+}
+
+/// Converts Duration to nanoseconds, saturating at `u64::MAX` on overflow
+/// instead of panicking.
+#[inline]
+pub fn saturating_duration_to_nanos(d: Duration) -> u64 {
+    checked_duration_to_nanos(d).unwrap_or(u64::MAX)
 }
\ No newline at end of file